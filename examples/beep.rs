@@ -1,4 +1,4 @@
-use clap::{command, Parser};
+use clap::Parser;
 use cpal::{
     traits::{DeviceTrait, HostTrait, StreamTrait},
     FromSample, Sample, SizedSample,
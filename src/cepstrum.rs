@@ -0,0 +1,61 @@
+/// Zero a real cepstrum on one side of `cutoff_quefrency`.
+///
+/// With `keep_low` the coefficients below the cutoff are kept (low-time liftering,
+/// the spectral envelope); otherwise those at or above it are kept (high-time
+/// liftering, the excitation). A real cepstrum is symmetric, so index `i` is
+/// treated as quefrency `min(i, N - i)` and both halves are liftered alike.
+pub fn liftering(cepstrum: &[f64], cutoff_quefrency: usize, keep_low: bool) -> Vec<f64> {
+    let len = cepstrum.len();
+    cepstrum
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let quefrency = i.min(len - i);
+            if (quefrency < cutoff_quefrency) == keep_low {
+                c
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Frequency whose period is `index` samples. Index 0 maps to infinity.
+pub fn quefrency_to_hz(index: usize, sample_rate: f64) -> f64 {
+    sample_rate / index as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CEPSTRUM: [f64; 8] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+    #[test]
+    fn test_liftering_keeps_both_halves() {
+        assert_eq!(
+            liftering(&CEPSTRUM, 2, true),
+            [1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 8.0]
+        );
+        assert_eq!(
+            liftering(&CEPSTRUM, 2, false),
+            [0.0, 0.0, 3.0, 4.0, 5.0, 6.0, 7.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_liftering_halves_sum_to_input() {
+        for cutoff in 0..=CEPSTRUM.len() {
+            let low = liftering(&CEPSTRUM, cutoff, true);
+            let high = liftering(&CEPSTRUM, cutoff, false);
+            let sum: Vec<f64> = low.iter().zip(&high).map(|(l, h)| l + h).collect();
+            assert_eq!(sum, CEPSTRUM);
+        }
+    }
+
+    #[test]
+    fn test_quefrency_to_hz() {
+        assert_eq!(quefrency_to_hz(100, 44100.0), 441.0);
+        assert_eq!(quefrency_to_hz(0, 44100.0), f64::INFINITY);
+    }
+}
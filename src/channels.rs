@@ -0,0 +1,64 @@
+//! Helpers for interleaved multi-channel buffers as delivered by cpal.
+//!
+//! A trailing partial frame is ignored. All functions panic if `channels` is 0.
+
+/// Split an interleaved buffer into one vector per channel.
+pub fn deinterleave(samples: &[f64], channels: usize) -> Vec<Vec<f64>> {
+    let frames = samples.len() / channels;
+    let mut output: Vec<Vec<f64>> = (0..channels).map(|_| Vec::with_capacity(frames)).collect();
+    for frame in samples.chunks_exact(channels) {
+        for (channel, &sample) in output.iter_mut().zip(frame) {
+            channel.push(sample);
+        }
+    }
+    output
+}
+
+/// Copy out channel `index`. Panics if `index >= channels`.
+pub fn extract_channel(samples: &[f64], channels: usize, index: usize) -> Vec<f64> {
+    assert!(index < channels, "Channel index {} out of range for {} channels.", index, channels);
+    samples.chunks_exact(channels).map(|frame| frame[index]).collect()
+}
+
+/// Average all channels of each frame into a mono signal.
+pub fn downmix_mono(samples: &[f64], channels: usize) -> Vec<f64> {
+    samples
+        .chunks_exact(channels)
+        .map(|frame| frame.iter().sum::<f64>() / channels as f64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Three stereo frames and a dangling left sample.
+    const STEREO: [f64; 7] = [1.0, -1.0, 2.0, -2.0, 3.0, -3.0, 4.0];
+
+    #[test]
+    fn test_deinterleave() {
+        let channels = deinterleave(&STEREO, 2);
+        assert_eq!(channels, vec![vec![1.0, 2.0, 3.0], vec![-1.0, -2.0, -3.0]]);
+        assert!(channels.iter().all(|channel| channel.capacity() >= 3));
+
+        assert_eq!(deinterleave(&[1.0, 2.0], 3), vec![Vec::<f64>::new(); 3]);
+    }
+
+    #[test]
+    fn test_extract_channel() {
+        assert_eq!(extract_channel(&STEREO, 2, 0), [1.0, 2.0, 3.0]);
+        assert_eq!(extract_channel(&STEREO, 2, 1), [-1.0, -2.0, -3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Channel index 2 out of range for 2 channels.")]
+    fn test_extract_channel_out_of_range() {
+        extract_channel(&STEREO, 2, 2);
+    }
+
+    #[test]
+    fn test_downmix_mono() {
+        assert_eq!(downmix_mono(&STEREO, 2), [0.0, 0.0, 0.0]);
+        assert_eq!(downmix_mono(&[1.0, 2.0, 6.0, 0.0, 0.0, 3.0], 3), [3.0, 1.0]);
+    }
+}
@@ -0,0 +1,182 @@
+use std::ops::MulAssign;
+
+use crate::units::{amplitude_to_db, db_to_power};
+
+/// Noise magnitude spectrum estimated online as the cumulative moving average of
+/// the frames fed to it, typically those from a region detected as silence.
+#[derive(Debug, Clone)]
+pub struct NoiseProfile {
+    profile: Vec<f64>,
+    frames: usize,
+}
+
+impl NoiseProfile {
+    pub fn new(fft_size: usize) -> Self {
+        NoiseProfile {
+            profile: vec![0.0; fft_size],
+            frames: 0,
+        }
+    }
+
+    /// Fold one magnitude frame into the average.
+    ///
+    /// Panics if the frame length differs from the `fft_size` given to `new`.
+    pub fn update(&mut self, magnitude_frame: &[f64]) {
+        assert_eq!(
+            magnitude_frame.len(),
+            self.profile.len(),
+            "Magnitude frame length doesn't match the noise profile size."
+        );
+
+        self.frames += 1;
+        let n = self.frames as f64;
+        for (average, &magnitude) in self.profile.iter_mut().zip(magnitude_frame) {
+            *average += (magnitude - *average) / n;
+        }
+    }
+
+    pub fn profile(&self) -> &[f64] {
+        &self.profile
+    }
+
+    /// Number of frames averaged so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn reset(&mut self) {
+        self.profile.fill(0.0);
+        self.frames = 0;
+    }
+}
+
+/// Floor applied to magnitudes before dividing, so empty bins don't give NaN or infinity.
+const MAGNITUDE_FLOOR: f64 = 1e-12;
+
+/// Per-bin a-posteriori SNR in dB: the signal-plus-noise magnitude against the
+/// noise-only magnitude. Bins holding only noise come out near 0 dB.
+///
+/// Panics if the two spectra differ in length.
+pub fn estimate_snr(signal_spectrum: &[f64], noise_spectrum: &[f64]) -> Vec<f64> {
+    assert_eq!(
+        signal_spectrum.len(),
+        noise_spectrum.len(),
+        "Signal and noise spectra must have the same number of bins."
+    );
+
+    signal_spectrum
+        .iter()
+        .zip(noise_spectrum)
+        .map(|(&signal, &noise)| amplitude_to_db(signal.max(MAGNITUDE_FLOOR), noise.max(MAGNITUDE_FLOOR)))
+        .collect()
+}
+
+/// Wiener gain `SNR / (SNR + 1)` for each bin, with the SNR given in dB.
+pub fn wiener_gain(snr_db: &[f64]) -> Vec<f64> {
+    snr_db
+        .iter()
+        .map(|&db| {
+            let snr = db_to_power(db, 1.0);
+            snr / (snr + 1.0)
+        })
+        .collect()
+}
+
+/// Scale each spectrum bin by its gain. Works with anything that can be scaled
+/// by an `f64`, such as real magnitudes or complex bins.
+///
+/// Panics if the spectrum and gains differ in length.
+pub fn apply_gain<T: MulAssign<f64>>(spectrum: &mut [T], gain: &[f64]) {
+    assert_eq!(spectrum.len(), gain.len(), "Spectrum and gain must have the same number of bins.");
+
+    for (bin, &g) in spectrum.iter_mut().zip(gain) {
+        *bin *= g;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generator::{Generator, NoiseGen},
+        level::snr,
+    };
+
+    #[test]
+    fn test_noise_profile_converges() {
+        let truth: Vec<f64> = (0..64).map(|k| 1.0 / (1.0 + k as f64)).collect();
+        let mut jitter = NoiseGen::new(11);
+        let mut profile = NoiseProfile::new(truth.len());
+
+        for _ in 0..2000 {
+            let frame: Vec<f64> = truth.iter().map(|&t| t * (1.0 + 0.5 * jitter.sample(0.0))).collect();
+            profile.update(&frame);
+        }
+
+        assert_eq!(profile.frames(), 2000);
+        for (estimate, t) in profile.profile().iter().zip(&truth) {
+            assert!((estimate - t).abs() < 0.05 * t, "{} != {}", estimate, t);
+        }
+    }
+
+    #[test]
+    fn test_noise_profile_is_mean() {
+        let mut profile = NoiseProfile::new(2);
+        profile.update(&[1.0, 4.0]);
+        profile.update(&[2.0, 0.0]);
+        profile.update(&[6.0, 2.0]);
+        assert_eq!(profile.profile(), &[3.0, 2.0]);
+
+        profile.reset();
+        assert_eq!((profile.profile(), profile.frames()), (&[0.0, 0.0][..], 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_noise_profile_size_mismatch() {
+        NoiseProfile::new(4).update(&[1.0; 3]);
+    }
+
+    #[test]
+    fn test_estimate_snr() {
+        let noise = vec![0.1; 16];
+        let mut signal = noise.clone();
+        // A tone 40 dB above the noise in bin 5.
+        signal[5] = 10.0;
+
+        let snr = estimate_snr(&signal, &noise);
+        assert!((snr[5] - 40.0).abs() < 1e-9);
+        assert!(snr.iter().enumerate().filter(|&(k, _)| k != 5).all(|(_, &s)| s.abs() < 1e-9));
+        assert!(estimate_snr(&[0.0], &[0.0])[0].is_finite());
+    }
+
+    #[test]
+    fn test_wiener_gain() {
+        let gain = wiener_gain(&[0.0, 20.0, -20.0]);
+        assert!((gain[0] - 0.5).abs() < 1e-12);
+        assert!((gain[1] - 100.0 / 101.0).abs() < 1e-12);
+        assert!((gain[2] - 0.01 / 1.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_gain_improves_snr() {
+        let mut clean = vec![0.0; 64];
+        clean[5] = 10.0;
+        clean[20] = 4.0;
+        let mut jitter = NoiseGen::new(3);
+        let noise: Vec<f64> = (0..64).map(|_| 0.2 + 0.1 * jitter.sample(0.0)).collect();
+        let noisy: Vec<f64> = clean.iter().zip(&noise).map(|(c, n)| c + n).collect();
+
+        let gain = wiener_gain(&estimate_snr(&noisy, &vec![0.2; 64]));
+        let mut filtered = noisy.clone();
+        apply_gain(&mut filtered, &gain);
+
+        assert!(snr(&clean, &filtered).unwrap() > snr(&clean, &noisy).unwrap() + 3.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_apply_gain_size_mismatch() {
+        apply_gain(&mut [1.0; 3], &[1.0; 2]);
+    }
+}
@@ -0,0 +1,68 @@
+use std::f64::consts::FRAC_PI_2;
+
+/// Multiply `signal` by a gain that moves linearly from `start_gain` on the first
+/// sample to `end_gain` on the last one.
+pub fn gain_ramp(signal: &mut [f64], start_gain: f64, end_gain: f64) {
+    let steps = signal.len().saturating_sub(1).max(1) as f64;
+    for (i, sample) in signal.iter_mut().enumerate() {
+        *sample *= start_gain + (end_gain - start_gain) * i as f64 / steps;
+    }
+}
+
+/// Equal-power crossfade from `a` to `b`.
+///
+/// `a` is scaled by `cos(θ)` and `b` by `sin(θ)` while θ goes from 0 to π/2, so
+/// the summed power of two uncorrelated signals stays constant through the fade.
+/// The fade spans the shorter of the two inputs, and the longer one is truncated.
+pub fn crossfade(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let len = a.len().min(b.len());
+    let steps = len.saturating_sub(1).max(1) as f64;
+    a.iter()
+        .zip(b)
+        .enumerate()
+        .map(|(i, (x, y))| {
+            let theta = FRAC_PI_2 * i as f64 / steps;
+            x * theta.cos() + y * theta.sin()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, SineGen};
+
+    #[test]
+    fn test_gain_ramp() {
+        let mut signal = vec![1.0; 5];
+        gain_ramp(&mut signal, 0.0, 1.0);
+        assert_eq!(signal, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn test_crossfade_gains_are_equal_power() {
+        let len = 101;
+        let fade_out = crossfade(&vec![1.0; len], &vec![0.0; len]);
+        let fade_in = crossfade(&vec![0.0; len], &vec![1.0; len]);
+
+        for (a, b) in fade_out.iter().zip(&fade_in) {
+            assert!((a * a + b * b - 1.0).abs() < 1e-12);
+        }
+        assert!((fade_out[50] - 0.5f64.sqrt()).abs() < 1e-12);
+        assert!((fade_in[50] - 0.5f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_crossfade_power_is_constant() {
+        let sample_rate = 8000.0;
+        let a = SineGen::new(300.0).render(1.0, sample_rate);
+        let b = SineGen::new(700.0).render(1.0, sample_rate);
+        let mixed = crossfade(&a, &b);
+
+        // Both inputs have a mean power of 0.5, and so should every stretch of the fade.
+        for chunk in mixed.chunks(800) {
+            let power = chunk.iter().map(|x| x * x).sum::<f64>() / chunk.len() as f64;
+            assert!((power - 0.5).abs() < 0.02, "{}", power);
+        }
+    }
+}
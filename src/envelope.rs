@@ -0,0 +1,100 @@
+/// One-pole smoothing coefficient for a time constant of `ms` milliseconds.
+/// A non-positive time gives 0, meaning the output follows the input instantly.
+pub(crate) fn ms_to_coef(ms: f64, sample_rate: f64) -> f64 {
+    let samples = ms * 0.001 * sample_rate;
+    if samples <= 0.0 {
+        0.0
+    } else {
+        (-1.0 / samples).exp()
+    }
+}
+
+/// Amplitude envelope follower with separate attack and release times.
+///
+/// The rectified input is smoothed by a one-pole filter which uses the attack
+/// coefficient while the level rises and the release coefficient while it falls.
+#[derive(Debug, Clone)]
+pub struct EnvelopeFollower {
+    attack_coef: f64,
+    release_coef: f64,
+    envelope: f64,
+}
+
+impl EnvelopeFollower {
+    pub fn new(attack_ms: f64, release_ms: f64, sample_rate: f64) -> Self {
+        EnvelopeFollower {
+            attack_coef: ms_to_coef(attack_ms, sample_rate),
+            release_coef: ms_to_coef(release_ms, sample_rate),
+            envelope: 0.0,
+        }
+    }
+
+    /// Current envelope level.
+    pub fn value(&self) -> f64 {
+        self.envelope
+    }
+
+    pub fn reset(&mut self) {
+        self.envelope = 0.0;
+    }
+
+    pub fn process_sample(&mut self, x: f64) -> f64 {
+        let rectified = x.abs();
+        let coef = if rectified > self.envelope {
+            self.attack_coef
+        } else {
+            self.release_coef
+        };
+        self.envelope = rectified + coef * (self.envelope - rectified);
+        self.envelope
+    }
+
+    /// Envelope of a block. State carries over between calls.
+    pub fn process(&mut self, samples: &[f64]) -> Vec<f64> {
+        samples.iter().map(|&x| self.process_sample(x)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, SineGen};
+
+    const SAMPLE_RATE: f64 = 8000.0;
+
+    #[test]
+    fn test_attack_release_asymmetry() {
+        // 1 ms attack and 100 ms release are 8 and 800 samples at 8 kHz.
+        let mut follower = EnvelopeFollower::new(1.0, 100.0, SAMPLE_RATE);
+
+        let rise = follower.process(&[-1.0; 8]);
+        assert!((rise[7] - (1.0 - (-1f64).exp())).abs() < 1e-12);
+        follower.process(&[1.0; 200]);
+        let peak = follower.value();
+        assert!(peak > 0.999_999);
+
+        let fall = follower.process(&[0.0; 800]);
+        assert!((fall[799] - peak * (-1f64).exp()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_zero_times_follow_instantly() {
+        let mut follower = EnvelopeFollower::new(0.0, 0.0, SAMPLE_RATE);
+        assert_eq!(follower.process(&[0.5, -0.25, 0.0]), [0.5, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn test_state_carries_over_blocks() {
+        let signal = SineGen::new(300.0).render(0.05, SAMPLE_RATE);
+        let whole = EnvelopeFollower::new(1.0, 20.0, SAMPLE_RATE).process(&signal);
+
+        let mut follower = EnvelopeFollower::new(1.0, 20.0, SAMPLE_RATE);
+        let mut blocks = follower.process(&signal[..100]);
+        blocks.extend(follower.process(&signal[100..]));
+        assert_eq!(whole, blocks);
+
+        follower.reset();
+        assert_eq!(follower.value(), 0.0);
+        assert_eq!(follower.process(&signal), whole);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::units::{amplitude_to_db, db_to_amplitude, power_to_db};
+
+/// Length of the RMS window used to decide whether the signal is active.
+const SILENCE_WINDOW_SECONDS: f64 = 0.01;
+
+/// Trim leading and trailing silence from `signal`.
+///
+/// A sample counts as active once the RMS of a short window next to it reaches
+/// `threshold_db` dBFS. At the leading edge the window ends at the sample, and at
+/// the trailing edge it starts there, so the window never reaches into the
+/// silence being measured. Each edge is then widened by up to one window to the
+/// outermost sample whose own level reaches the threshold, which recovers the
+/// quiet samples at the very start and end of the content.
+///
+/// Returns the trimmed signal with its start (inclusive) and end (exclusive)
+/// offsets into `signal`. Any detected content gives at least one sample. A
+/// signal that is silent throughout gives an empty vector and `(0, 0)`.
+pub fn trim_silence(signal: &[f64], threshold_db: f64, sample_rate: f64) -> (Vec<f64>, usize, usize) {
+    let window = ((SILENCE_WINDOW_SECONDS * sample_rate) as usize).max(1);
+    let threshold = db_to_amplitude(threshold_db, 1.0);
+
+    // prefix[i] is the energy of signal[..i].
+    let mut prefix = Vec::with_capacity(signal.len() + 1);
+    prefix.push(0.0);
+    for sample in signal {
+        prefix.push(prefix[prefix.len() - 1] + sample * sample);
+    }
+    let rms = |from: usize, to: usize| ((prefix[to] - prefix[from]) / window as f64).sqrt();
+
+    let start = (0..signal.len()).find(|&i| rms((i + 1).saturating_sub(window), i + 1) >= threshold);
+    let Some(start) = start else {
+        return (Vec::new(), 0, 0);
+    };
+    let end = (start..signal.len())
+        .rev()
+        .find(|&i| rms(i, (i + window).min(signal.len())) >= threshold)
+        .map_or(start + 1, |i| i + 1);
+
+    let start = (start.saturating_sub(window)..start)
+        .find(|&i| signal[i].abs() >= threshold)
+        .unwrap_or(start);
+    let end = (end..(end + window).min(signal.len()))
+        .rev()
+        .find(|&i| signal[i].abs() >= threshold)
+        .map_or(end, |i| i + 1);
+
+    (signal[start..end].to_vec(), start, end)
+}
+
+/// Pitch range searched by [`hnr`] for the periodic component.
+const HNR_MIN_PITCH: f64 = 75.0;
+const HNR_MAX_PITCH: f64 = 1000.0;
+
+/// Harmonic-to-noise ratio in dB.
+///
+/// Takes the highest normalized autocorrelation `r` at lags corresponding to
+/// pitches between 75 Hz and 1 kHz, and returns `10 * log10(r / (1 - r))`. A
+/// clean periodic signal gives a large positive value. Noise gives a strongly
+/// negative one, since none of its energy is periodic. Returns
+/// `f64::NEG_INFINITY` if the signal is too short or shows no positive correlation.
+pub fn hnr(samples: &[f64], sample_rate: f64) -> f64 {
+    let min_lag = ((sample_rate / HNR_MAX_PITCH) as usize).max(1);
+    let max_lag = ((sample_rate / HNR_MIN_PITCH) as usize).min(samples.len() / 2);
+
+    let mut best = 0f64;
+    for lag in min_lag..=max_lag {
+        let (head, tail) = (&samples[..samples.len() - lag], &samples[lag..]);
+        let cross: f64 = head.iter().zip(tail).map(|(a, b)| a * b).sum();
+        let energy = (head.iter().map(|x| x * x).sum::<f64>() * tail.iter().map(|x| x * x).sum::<f64>()).sqrt();
+        if energy > 0.0 {
+            best = best.max(cross / energy);
+        }
+    }
+
+    if best <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    let r = best.min(1.0 - 1e-12);
+    10.0 * (r / (1.0 - r)).log10()
+}
+
+/// Frame-wise voice activity detection on short-time energy.
+///
+/// Each frame of `frame_size` samples, advanced by `hop`, is voiced when its mean
+/// power reaches `energy_threshold_db` dBFS. A trailing partial frame is ignored.
+///
+/// Panics if `hop` is 0.
+pub fn vad(samples: &[f64], frame_size: usize, hop: usize, energy_threshold_db: f64) -> Vec<bool> {
+    assert!(hop > 0, "Hop must be positive.");
+
+    if frame_size == 0 || samples.len() < frame_size {
+        return Vec::new();
+    }
+
+    (0..=samples.len() - frame_size)
+        .step_by(hop)
+        .map(|start| {
+            let frame = &samples[start..start + frame_size];
+            let power = frame.iter().map(|x| x * x).sum::<f64>() / frame_size as f64;
+            power_to_db(power, 1.0) >= energy_threshold_db
+        })
+        .collect()
+}
+
+/// Keep the samples from the first to the last voiced frame found by [`vad`].
+///
+/// Panics if `hop` is 0.
+pub fn trim_unvoiced(samples: &[f64], frame_size: usize, hop: usize, energy_threshold_db: f64) -> Vec<f64> {
+    let voiced = vad(samples, frame_size, hop, energy_threshold_db);
+    match (voiced.iter().position(|&v| v), voiced.iter().rposition(|&v| v)) {
+        (Some(first), Some(last)) => samples[first * hop..last * hop + frame_size].to_vec(),
+        _ => Vec::new(),
+    }
+}
+
+/// Linear A-weighting gain (IEC 61672) at `freq`, normalized to 1.0 at 1 kHz.
+fn a_weighting_gain(freq: f64) -> f64 {
+    let f2 = freq * freq;
+    let r = 12194f64.powi(2) * f2 * f2
+        / ((f2 + 20.6f64.powi(2))
+            * ((f2 + 107.7f64.powi(2)) * (f2 + 737.9f64.powi(2))).sqrt()
+            * (f2 + 12194f64.powi(2)));
+    // +2.00 dB brings the curve to 0 dB at 1 kHz.
+    r * db_to_amplitude(2.0, 1.0)
+}
+
+/// A-weighting (IEC 61672) at `freq`, in dB.
+pub fn a_weighting_db(freq: f64) -> f64 {
+    amplitude_to_db(a_weighting_gain(freq), 1.0)
+}
+
+/// Apply the A-weighting curve to a `(frequency, magnitude)` spectrum.
+pub fn a_weighting(spectrum: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    spectrum
+        .iter()
+        .map(|&(freq, magnitude)| (freq, magnitude * a_weighting_gain(freq)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generator::{Generator, NoiseGen, SineGen},
+        units::DB_FLOOR,
+    };
+
+    const SAMPLE_RATE: f64 = 44100.0;
+
+    fn padded_tone(lead: usize, tone: usize, trail: usize) -> Vec<f64> {
+        // A small phase offset keeps the first sample off the zero crossing, which
+        // would otherwise be indistinguishable from the silence before it.
+        let mut sine = SineGen::new(440.0);
+        sine.phase = 0.1;
+        let mut signal = vec![0.0; lead];
+        signal.extend(sine.render(tone as f64 / SAMPLE_RATE, SAMPLE_RATE));
+        signal.extend(vec![0.0; trail]);
+        signal
+    }
+
+    #[test]
+    fn test_trim_silence() {
+        let signal = padded_tone(1000, 4410, 2000);
+        let (trimmed, start, end) = trim_silence(&signal, -40.0, SAMPLE_RATE);
+
+        assert_eq!((start, end), (1000, 5410));
+        assert_eq!(trimmed, signal[1000..5410]);
+    }
+
+    #[test]
+    fn test_trim_silence_all_silent() {
+        assert_eq!(trim_silence(&[0.0; 1000], -40.0, SAMPLE_RATE), (Vec::new(), 0, 0));
+        assert_eq!(trim_silence(&[], -40.0, SAMPLE_RATE), (Vec::new(), 0, 0));
+    }
+
+    #[test]
+    fn test_trim_silence_keeps_detected_content() {
+        // The trailing window at the spike also holds the earlier quiet samples,
+        // so the spike is detected even though its leading window alone is below
+        // the threshold.
+        let mut signal = vec![0.008; 441];
+        signal.push(0.15);
+        signal.extend(vec![0.0; 1000]);
+
+        assert_eq!(trim_silence(&signal, -40.0, SAMPLE_RATE), (vec![0.15], 441, 442));
+    }
+
+    #[test]
+    fn test_hnr() {
+        let sine = SineGen::new(440.0).render(0.2, SAMPLE_RATE);
+        let noise = NoiseGen::new(7).render(0.2, SAMPLE_RATE);
+
+        assert!(hnr(&sine, SAMPLE_RATE) > 40.0);
+        // None of the noise energy is periodic, so the ratio is well below 0 dB.
+        assert!(hnr(&noise, SAMPLE_RATE) < 0.0);
+        assert_eq!(hnr(&[0.0; 2000], SAMPLE_RATE), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_vad_gated_signal() {
+        let signal = padded_tone(2048, 4096, 2048);
+        let voiced = vad(&signal, 1024, 512, -30.0);
+
+        assert_eq!(voiced.len(), (signal.len() - 1024) / 512 + 1);
+        assert!(voiced[..3].iter().all(|&v| !v));
+        assert!(voiced[4..11].iter().all(|&v| v));
+        assert!(voiced[voiced.len() - 3..].iter().all(|&v| !v));
+    }
+
+    #[test]
+    fn test_trim_unvoiced() {
+        let signal = padded_tone(2048, 4096, 2048);
+        let trimmed = trim_unvoiced(&signal, 1024, 512, -30.0);
+
+        // Frames straddling the edges are voiced, so up to a frame of silence remains.
+        assert!(trimmed.len() >= 4096 && trimmed.len() <= 4096 + 2 * 1024);
+        assert!(trim_unvoiced(&[0.0; 4096], 1024, 512, -30.0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Hop must be positive.")]
+    fn test_vad_zero_hop() {
+        vad(&[0.0; 4096], 1024, 0, -30.0);
+    }
+
+    #[test]
+    fn test_a_weighting() {
+        assert!(a_weighting_db(1000.0).abs() < 0.01);
+        assert!((a_weighting_db(50.0) + 30.2).abs() < 0.2);
+        assert_eq!(a_weighting_db(0.0), DB_FLOOR);
+
+        let weighted = a_weighting(&[(0.0, 1.0), (1000.0, 2.0)]);
+        assert_eq!(weighted[0], (0.0, 0.0));
+        assert!((weighted[1].1 - 2.0).abs() < 1e-3);
+    }
+}
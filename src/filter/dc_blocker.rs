@@ -0,0 +1,72 @@
+/// Classic DC blocker `y[n] = x[n] - x[n-1] + R·y[n-1]`.
+///
+/// `r` just below 1.0 (typically 0.995) gives a highpass corner of roughly
+/// `(1 - r)·fs / 2π`.
+#[derive(Debug, Clone)]
+pub struct DcBlocker {
+    r: f64,
+    x1: f64,
+    y1: f64,
+}
+
+impl DcBlocker {
+    pub fn new(r: f64) -> Self {
+        DcBlocker { r, x1: 0.0, y1: 0.0 }
+    }
+
+    pub fn process_sample(&mut self, x: f64) -> f64 {
+        let y = x - self.x1 + self.r * self.y1;
+        self.x1 = x;
+        self.y1 = y;
+        y
+    }
+
+    /// Filter a block. State carries over between calls.
+    pub fn process(&mut self, x: &[f64]) -> Vec<f64> {
+        x.iter().map(|&sample| self.process_sample(sample)).collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.x1 = 0.0;
+        self.y1 = 0.0;
+    }
+}
+
+impl Default for DcBlocker {
+    fn default() -> Self {
+        DcBlocker::new(0.995)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, SineGen};
+
+    #[test]
+    fn test_constant_decays_to_zero() {
+        let output = DcBlocker::default().process(&[0.5; 5000]);
+        assert_eq!(output[0], 0.5);
+        // The step response is 0.5 * r^n.
+        assert!((output[100] - 0.5 * 0.995f64.powi(100)).abs() < 1e-12);
+        assert!(output[4999].abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_state_carries_over_blocks() {
+        let signal: Vec<f64> = SineGen::new(300.0)
+            .render(0.01, 44100.0)
+            .iter()
+            .map(|x| x + 0.2)
+            .collect();
+        let whole = DcBlocker::default().process(&signal);
+
+        let mut blocker = DcBlocker::default();
+        let mut blocks = blocker.process(&signal[..100]);
+        blocks.extend(blocker.process(&signal[100..]));
+        assert_eq!(whole, blocks);
+
+        blocker.reset();
+        assert_eq!(blocker.process(&signal), whole);
+    }
+}
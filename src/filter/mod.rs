@@ -0,0 +1,3 @@
+pub mod dc_blocker;
+pub mod nlms;
+pub mod onepole;
@@ -0,0 +1,109 @@
+/// Normalized LMS adaptive FIR filter.
+///
+/// Each call to `adapt` filters one input sample, compares the result with the
+/// desired sample and nudges the weights towards it. The step is normalized by
+/// the input power, so `mu` in `(0, 2)` converges regardless of the signal level.
+#[derive(Debug, Clone)]
+pub struct NlmsFilter {
+    weights: Vec<f64>,
+    history: Vec<f64>,
+    mu: f64,
+    epsilon: f64,
+}
+
+impl NlmsFilter {
+    pub fn new(num_taps: usize, mu: f64) -> Self {
+        NlmsFilter {
+            weights: vec![0.0; num_taps],
+            history: vec![0.0; num_taps],
+            mu,
+            epsilon: 1e-8,
+        }
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Zero the weights and the input history.
+    pub fn reset(&mut self) {
+        self.weights.fill(0.0);
+        self.history.fill(0.0);
+    }
+
+    /// Returns `(output, error)` for one sample, where `error = desired - output`.
+    pub fn adapt(&mut self, input: f64, desired: f64) -> (f64, f64) {
+        if self.history.is_empty() {
+            return (0.0, desired);
+        }
+
+        self.history.rotate_right(1);
+        self.history[0] = input;
+
+        let output: f64 = self.weights.iter().zip(&self.history).map(|(w, x)| w * x).sum();
+        let error = desired - output;
+
+        let power: f64 = self.history.iter().map(|x| x * x).sum();
+        let step = self.mu * error / (power + self.epsilon);
+        for (w, x) in self.weights.iter_mut().zip(&self.history) {
+            *w += step * x;
+        }
+
+        (output, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Uniform white noise in [-1, 1) from a xorshift64 generator.
+    fn white_noise(len: usize, mut state: u64) -> Vec<f64> {
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_identifies_fir_system() {
+        let system = [0.5, -0.3, 0.2, 0.1];
+        let input = white_noise(2000, 1);
+
+        let mut filter = NlmsFilter::new(system.len(), 0.5);
+        let errors: Vec<f64> = input
+            .iter()
+            .enumerate()
+            .map(|(n, &x)| {
+                let desired: f64 = system
+                    .iter()
+                    .enumerate()
+                    .filter(|&(k, _)| k <= n)
+                    .map(|(k, h)| h * input[n - k])
+                    .sum();
+                filter.adapt(x, desired).1.abs()
+            })
+            .collect();
+
+        for (w, h) in filter.weights().iter().zip(&system) {
+            assert!((w - h).abs() < 1e-9, "{} != {}", w, h);
+        }
+
+        let early = errors[..50].iter().sum::<f64>() / 50.0;
+        let late = errors[errors.len() - 50..].iter().sum::<f64>() / 50.0;
+        assert!(late < early * 1e-6);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut filter = NlmsFilter::new(2, 0.5);
+        filter.adapt(1.0, 1.0);
+        filter.reset();
+        assert_eq!(filter.weights(), &[0.0, 0.0]);
+        assert_eq!(filter.adapt(1.0, 0.0), (0.0, 0.0));
+    }
+}
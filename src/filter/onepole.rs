@@ -0,0 +1,106 @@
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnePoleMode {
+    Lowpass,
+    Highpass,
+}
+
+/// One-pole filter with a single coefficient `a = exp(-2π·fc/fs)`.
+///
+/// Lowpass: `y[n] = (1 - a)·x[n] + a·y[n-1]`.
+/// Highpass: `y[n] = a·(y[n-1] + x[n] - x[n-1])`, which blocks DC.
+#[derive(Debug, Clone)]
+pub struct OnePole {
+    mode: OnePoleMode,
+    a: f64,
+    x1: f64,
+    y1: f64,
+}
+
+impl OnePole {
+    pub fn new(mode: OnePoleMode, sample_rate: f64, cutoff: f64) -> Self {
+        OnePole {
+            mode,
+            a: (-2.0 * PI * cutoff / sample_rate).exp(),
+            x1: 0.0,
+            y1: 0.0,
+        }
+    }
+
+    pub fn lowpass(sample_rate: f64, cutoff: f64) -> Self {
+        OnePole::new(OnePoleMode::Lowpass, sample_rate, cutoff)
+    }
+
+    pub fn highpass(sample_rate: f64, cutoff: f64) -> Self {
+        OnePole::new(OnePoleMode::Highpass, sample_rate, cutoff)
+    }
+
+    pub fn mode(&self) -> OnePoleMode {
+        self.mode
+    }
+
+    pub fn process_sample(&mut self, x: f64) -> f64 {
+        let y = match self.mode {
+            OnePoleMode::Lowpass => (1.0 - self.a) * x + self.a * self.y1,
+            OnePoleMode::Highpass => self.a * (self.y1 + x - self.x1),
+        };
+        self.x1 = x;
+        self.y1 = y;
+        y
+    }
+
+    /// Filter a block. State carries over between calls.
+    pub fn process(&mut self, x: &[f64]) -> Vec<f64> {
+        x.iter().map(|&sample| self.process_sample(sample)).collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.x1 = 0.0;
+        self.y1 = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, SineGen};
+
+    const SAMPLE_RATE: f64 = 44100.0;
+
+    #[test]
+    fn test_highpass_removes_dc() {
+        let signal: Vec<f64> = SineGen::new(1000.0)
+            .render(1.0, SAMPLE_RATE)
+            .iter()
+            .map(|x| x + 0.5)
+            .collect();
+        let output = OnePole::highpass(SAMPLE_RATE, 20.0).process(&signal);
+
+        let tail = &output[output.len() - 4410..];
+        let mean = tail.iter().sum::<f64>() / tail.len() as f64;
+        assert!(mean.abs() < 1e-3);
+        // The 1 kHz tone passes almost untouched.
+        assert!(tail.iter().fold(0f64, |peak, x| peak.max(x.abs())) > 0.99);
+    }
+
+    #[test]
+    fn test_lowpass_settles_to_dc() {
+        let output = OnePole::lowpass(SAMPLE_RATE, 20.0).process(&[0.5; 44100]);
+        assert!((output[output.len() - 1] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_state_carries_over_blocks() {
+        let signal = SineGen::new(300.0).render(0.01, SAMPLE_RATE);
+        let whole = OnePole::highpass(SAMPLE_RATE, 100.0).process(&signal);
+
+        let mut filter = OnePole::highpass(SAMPLE_RATE, 100.0);
+        let mut blocks = filter.process(&signal[..100]);
+        blocks.extend(filter.process(&signal[100..]));
+        assert_eq!(whole, blocks);
+
+        filter.reset();
+        assert_eq!(filter.process(&signal), whole);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    envelope::{ms_to_coef, EnvelopeFollower},
+    units::db_to_amplitude,
+};
+
+/// How far below the open threshold the envelope must fall before the gate closes.
+const HYSTERESIS_DB: f64 = 6.0;
+
+/// Noise gate which mutes the signal while its envelope stays below a threshold.
+///
+/// The gate opens as soon as the envelope reaches `threshold_db` (dBFS) and closes
+/// only after the envelope has stayed `HYSTERESIS_DB` below it for `hold_ms`, so a
+/// signal hovering around the threshold doesn't make it chatter. `attack_ms` and
+/// `release_ms` control how fast the gain ramps up when opening and down when closing.
+#[derive(Debug, Clone)]
+pub struct NoiseGate {
+    open_threshold: f64,
+    close_threshold: f64,
+    attack_coef: f64,
+    release_coef: f64,
+    hold_samples: usize,
+    envelope: EnvelopeFollower,
+    gain: f64,
+    hold_counter: usize,
+    open: bool,
+}
+
+impl NoiseGate {
+    pub fn new(threshold_db: f64, attack_ms: f64, hold_ms: f64, release_ms: f64, sample_rate: f64) -> Self {
+        NoiseGate {
+            open_threshold: db_to_amplitude(threshold_db, 1.0),
+            close_threshold: db_to_amplitude(threshold_db - HYSTERESIS_DB, 1.0),
+            attack_coef: ms_to_coef(attack_ms, sample_rate),
+            release_coef: ms_to_coef(release_ms, sample_rate),
+            hold_samples: (hold_ms * 0.001 * sample_rate).round() as usize,
+            // Instant attack, so the gate opens on the first loud sample.
+            envelope: EnvelopeFollower::new(0.0, release_ms, sample_rate),
+            gain: 0.0,
+            hold_counter: 0,
+            open: false,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Clear the envelope and close the gate.
+    pub fn reset(&mut self) {
+        self.envelope.reset();
+        self.gain = 0.0;
+        self.hold_counter = 0;
+        self.open = false;
+    }
+
+    pub fn process_sample(&mut self, x: f64) -> f64 {
+        let envelope = self.envelope.process_sample(x);
+
+        if envelope >= self.open_threshold {
+            self.open = true;
+            self.hold_counter = self.hold_samples;
+        } else if self.open && envelope < self.close_threshold {
+            if self.hold_counter > 0 {
+                self.hold_counter -= 1;
+            } else {
+                self.open = false;
+            }
+        }
+
+        let (target, coef) = if self.open {
+            (1.0, self.attack_coef)
+        } else {
+            (0.0, self.release_coef)
+        };
+        self.gain = target + coef * (self.gain - target);
+
+        x * self.gain
+    }
+
+    /// Gate a block of samples. The state carries over between calls, so a
+    /// stream can be fed block by block.
+    pub fn process(&mut self, samples: &[f64]) -> Vec<f64> {
+        samples.iter().map(|&x| self.process_sample(x)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    const SAMPLE_RATE: f64 = 8000.0;
+
+    fn peak(samples: &[f64]) -> f64 {
+        samples.iter().fold(0f64, |peak, x| peak.max(x.abs()))
+    }
+
+    #[test]
+    fn test_intermittent_silence() {
+        let mut signal: Vec<f64> = (0..8000)
+            .map(|i| 0.5 * (2.0 * PI * 440.0 * i as f64 / SAMPLE_RATE).sin())
+            .collect();
+        // Replace the middle with a -60 dBFS noise floor.
+        for sample in &mut signal[2000..5000] {
+            *sample = 0.001;
+        }
+
+        let mut gate = NoiseGate::new(-30.0, 1.0, 10.0, 20.0, SAMPLE_RATE);
+        let output = gate.process(&signal);
+
+        assert!(peak(&output[1000..2000]) > 0.49);
+        assert!(peak(&output[3500..5000]) < 1e-5);
+        assert!(peak(&output[6000..]) > 0.49);
+    }
+
+    fn samples_until_closed(hold_ms: f64) -> usize {
+        let mut gate = NoiseGate::new(-30.0, 1.0, hold_ms, 20.0, SAMPLE_RATE);
+        gate.process(&[0.5; 100]);
+        assert!(gate.is_open());
+        (1..).find(|_| {
+            gate.process_sample(0.0);
+            !gate.is_open()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_hold_delays_closing() {
+        // 50 ms at 8 kHz.
+        assert_eq!(samples_until_closed(50.0) - samples_until_closed(0.0), 400);
+    }
+
+    #[test]
+    fn test_hysteresis() {
+        // Between the -26 dB close threshold and the -20 dB open threshold.
+        let level = db_to_amplitude(-23.0, 1.0);
+
+        let mut closed = NoiseGate::new(-20.0, 1.0, 0.0, 20.0, SAMPLE_RATE);
+        closed.process(&[level; 1000]);
+        assert!(!closed.is_open());
+
+        let mut opened = NoiseGate::new(-20.0, 1.0, 0.0, 20.0, SAMPLE_RATE);
+        opened.process(&[0.5; 100]);
+        opened.process(&[level; 1000]);
+        assert!(opened.is_open());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut gate = NoiseGate::new(-30.0, 1.0, 10.0, 20.0, SAMPLE_RATE);
+        gate.process(&[0.5; 100]);
+        gate.reset();
+        assert!(!gate.is_open());
+        assert_eq!(gate.process_sample(0.0), 0.0);
+    }
+}
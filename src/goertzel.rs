@@ -0,0 +1,92 @@
+use std::f64::consts::PI;
+
+/// Squared magnitude of the DFT of `samples` at `target_freq`, evaluated with the
+/// Goertzel recurrence. The frequency doesn't need to fall on a bin centre.
+pub fn goertzel_power(samples: &[f64], target_freq: f64, sample_rate: f64) -> f64 {
+    let coef = 2.0 * (2.0 * PI * target_freq / sample_rate).cos();
+    let (mut s1, mut s2) = (0.0, 0.0);
+    for &x in samples {
+        let s0 = x + coef * s1 - s2;
+        s2 = s1;
+        s1 = s0;
+    }
+    s1 * s1 + s2 * s2 - coef * s1 * s2
+}
+
+const DTMF_LOW: [f64; 4] = [697.0, 770.0, 852.0, 941.0];
+const DTMF_HIGH: [f64; 4] = [1209.0, 1336.0, 1477.0, 1633.0];
+const DTMF_KEYS: [[char; 4]; 4] = [
+    ['1', '2', '3', 'A'],
+    ['4', '5', '6', 'B'],
+    ['7', '8', '9', 'C'],
+    ['*', '0', '#', 'D'],
+];
+/// How many times stronger the winning tone must be than the rest of its group.
+const DTMF_DOMINANCE: f64 = 4.0;
+/// Minimum share of the signal energy the winning tone must carry. A pure tone gives 0.5.
+const DTMF_MIN_SHARE: f64 = 0.05;
+
+/// Index of the strongest tone in `powers` if it clearly dominates the others
+/// and carries enough of the signal energy `total`.
+fn dominant_tone(powers: &[f64; 4], total: f64) -> Option<usize> {
+    let (index, &best) = powers.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))?;
+    let dominant = powers
+        .iter()
+        .enumerate()
+        .all(|(i, &p)| i == index || best >= DTMF_DOMINANCE * p);
+    (dominant && best >= DTMF_MIN_SHARE * total).then_some(index)
+}
+
+/// Decode a single DTMF digit from a block of samples, or `None` if no clear
+/// low-group/high-group tone pair is present.
+pub fn decode_dtmf(samples: &[f64], sample_rate: f64) -> Option<char> {
+    // Goertzel power of a tone scales with N times its energy, so normalize by that.
+    let total = samples.len() as f64 * samples.iter().map(|x| x * x).sum::<f64>();
+    if total == 0.0 {
+        return None;
+    }
+
+    let low = DTMF_LOW.map(|f| goertzel_power(samples, f, sample_rate));
+    let high = DTMF_HIGH.map(|f| goertzel_power(samples, f, sample_rate));
+
+    let row = dominant_tone(&low, total)?;
+    let column = dominant_tone(&high, total)?;
+    Some(DTMF_KEYS[row][column])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, SineGen};
+
+    const SAMPLE_RATE: f64 = 8000.0;
+
+    fn tone(freq: f64) -> Vec<f64> {
+        SineGen::new(freq).render(0.05, SAMPLE_RATE)
+    }
+
+    fn tone_pair(low: f64, high: f64) -> Vec<f64> {
+        tone(low).iter().zip(tone(high)).map(|(l, h)| l + h).collect()
+    }
+
+    #[test]
+    fn test_goertzel_power_peaks_at_tone() {
+        let signal = tone_pair(1000.0, 3000.0);
+        assert!(goertzel_power(&signal, 1000.0, SAMPLE_RATE) > 1000.0 * goertzel_power(&signal, 2000.0, SAMPLE_RATE));
+    }
+
+    #[test]
+    fn test_decode_dtmf() {
+        assert_eq!(decode_dtmf(&tone_pair(697.0, 1209.0), SAMPLE_RATE), Some('1'));
+        assert_eq!(decode_dtmf(&tone_pair(941.0, 1336.0), SAMPLE_RATE), Some('0'));
+        assert_eq!(decode_dtmf(&tone_pair(770.0, 1477.0), SAMPLE_RATE), Some('6'));
+        assert_eq!(decode_dtmf(&tone_pair(852.0, 1633.0), SAMPLE_RATE), Some('C'));
+    }
+
+    #[test]
+    fn test_decode_dtmf_rejects_non_digits() {
+        assert_eq!(decode_dtmf(&tone(697.0), SAMPLE_RATE), None);
+        assert_eq!(decode_dtmf(&[0.0; 400], SAMPLE_RATE), None);
+        assert_eq!(decode_dtmf(&[], SAMPLE_RATE), None);
+    }
+}
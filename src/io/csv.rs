@@ -0,0 +1,50 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::{ensure, Result};
+
+/// Write a signal as `time,amplitude` rows, with the time in seconds.
+pub fn export_waveform_csv(path: &Path, signal: &[f64], sample_rate: f64) -> Result<()> {
+    ensure!(sample_rate > 0.0, "Sample rate must be positive, got {}.", sample_rate);
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "time,amplitude")?;
+    for (i, value) in signal.iter().enumerate() {
+        writeln!(writer, "{},{}", i as f64 / sample_rate, value)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_export_waveform_csv() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("audio_waveform_{}.csv", std::process::id()));
+        let signal = [0.0, 0.5, -0.25, 1.0];
+        export_waveform_csv(&path, &signal, 4.0)?;
+
+        let content = fs::read_to_string(&path)?;
+        fs::remove_file(&path)?;
+
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), signal.len() + 1);
+        assert_eq!(lines[0], "time,amplitude");
+        assert_eq!(lines[1..], ["0,0", "0.25,0.5", "0.5,-0.25", "0.75,1"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_waveform_csv_rejects_bad_sample_rate() {
+        let path = std::env::temp_dir().join("audio_waveform_never_written.csv");
+        assert!(export_waveform_csv(&path, &[0.0], 0.0).is_err());
+        assert!(!path.exists());
+    }
+}
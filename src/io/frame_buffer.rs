@@ -0,0 +1,126 @@
+/// Collects arbitrarily sized input blocks and hands them out as fixed-size
+/// frames, each starting `hop` samples after the previous one.
+///
+/// This decouples the block size chosen by the audio device from the frame size
+/// used for analysis. Samples are dropped once no future frame can use them.
+#[derive(Debug, Clone)]
+pub struct FrameBuffer {
+    frame_size: usize,
+    hop: usize,
+    buffer: Vec<f64>,
+    // Samples still to drop when `hop` is larger than what was buffered.
+    skip: usize,
+}
+
+impl FrameBuffer {
+    /// Panics if `frame_size` or `hop` is 0.
+    pub fn new(frame_size: usize, hop: usize) -> Self {
+        assert!(frame_size > 0 && hop > 0, "Frame size and hop must be positive.");
+
+        FrameBuffer {
+            frame_size,
+            hop,
+            buffer: Vec::with_capacity(frame_size * 2),
+            skip: 0,
+        }
+    }
+
+    pub fn frame_size(&self) -> usize {
+        self.frame_size
+    }
+
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    /// Number of samples buffered towards the next frame.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn push(&mut self, samples: &[f64]) {
+        let skipped = self.skip.min(samples.len());
+        self.skip -= skipped;
+        self.buffer.extend_from_slice(&samples[skipped..]);
+    }
+
+    /// Next complete frame, or `None` until enough samples have been pushed.
+    pub fn pop_frame(&mut self) -> Option<Vec<f64>> {
+        if self.buffer.len() < self.frame_size {
+            return None;
+        }
+
+        let frame = self.buffer[..self.frame_size].to_vec();
+        let consumed = self.hop.min(self.buffer.len());
+        self.buffer.drain(..consumed);
+        self.skip = self.hop - consumed;
+
+        Some(frame)
+    }
+
+    pub fn clear(&mut self) {
+        self.buffer.clear();
+        self.skip = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(len: usize) -> Vec<f64> {
+        (0..len).map(|i| i as f64).collect()
+    }
+
+    fn collect_frames(buffer: &mut FrameBuffer, input: &[f64], blocks: &[usize]) -> Vec<Vec<f64>> {
+        let mut frames = Vec::new();
+        let mut position = 0;
+        for &size in blocks {
+            buffer.push(&input[position..position + size]);
+            position += size;
+            while let Some(frame) = buffer.pop_frame() {
+                frames.push(frame);
+            }
+        }
+        frames
+    }
+
+    fn expected_frames(input: &[f64], frame_size: usize, hop: usize) -> Vec<Vec<f64>> {
+        (0..)
+            .map(|k| k * hop)
+            .take_while(|start| start + frame_size <= input.len())
+            .map(|start| input[start..start + frame_size].to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_odd_blocks() {
+        let input = ramp(2024);
+        let mut buffer = FrameBuffer::new(1024, 512);
+        let frames = collect_frames(&mut buffer, &input, &[300, 700, 1024]);
+
+        assert_eq!(frames, expected_frames(&input, 1024, 512));
+        assert_eq!(frames.len(), 2);
+        assert_eq!(buffer.buffered(), 2024 - 2 * 512);
+    }
+
+    #[test]
+    fn test_hop_larger_than_frame() {
+        let input = ramp(6000);
+        let mut buffer = FrameBuffer::new(1024, 1500);
+        let frames = collect_frames(&mut buffer, &input, &[300, 700, 1024, 5, 2000, 1971]);
+
+        assert_eq!(frames, expected_frames(&input, 1024, 1500));
+        assert_eq!(frames.len(), 4);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut buffer = FrameBuffer::new(4, 8);
+        buffer.push(&[1.0; 4]);
+        assert!(buffer.pop_frame().is_some());
+        buffer.clear();
+        buffer.push(&[2.0; 4]);
+        assert_eq!(buffer.pop_frame(), Some(vec![2.0; 4]));
+    }
+}
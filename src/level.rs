@@ -0,0 +1,164 @@
+use anyhow::{ensure, Result};
+
+use crate::units::power_to_db;
+
+/// Clipping summary returned by [`clipping_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClipStats {
+    /// Number of samples whose magnitude is at or above the threshold.
+    pub count: usize,
+    /// `count` as a fraction of the signal length.
+    pub ratio: f64,
+    /// Number of runs of consecutive clipped samples.
+    pub runs: usize,
+}
+
+/// Count samples with `|x| >= threshold` and how many consecutive runs they form.
+pub fn clipping_stats(samples: &[f64], threshold: f64) -> ClipStats {
+    let mut count = 0;
+    let mut runs = 0;
+    let mut in_run = false;
+
+    for sample in samples {
+        let clipped = sample.abs() >= threshold;
+        if clipped {
+            count += 1;
+            if !in_run {
+                runs += 1;
+            }
+        }
+        in_run = clipped;
+    }
+
+    let ratio = if samples.is_empty() {
+        0.0
+    } else {
+        count as f64 / samples.len() as f64
+    };
+
+    ClipStats { count, ratio, runs }
+}
+
+/// Histogram of sample values over the signal's min-to-max range, as
+/// `(bin centre, count)` pairs.
+pub fn amplitude_histogram(samples: &[f64], bins: usize) -> Vec<(f64, usize)> {
+    if samples.is_empty() || bins == 0 {
+        return Vec::new();
+    }
+
+    let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let width = (max - min) / bins as f64;
+
+    let mut counts = vec![0; bins];
+    for sample in samples {
+        let index = if width > 0.0 {
+            (((sample - min) / width) as usize).min(bins - 1)
+        } else {
+            0
+        };
+        counts[index] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (min + (i as f64 + 0.5) * width, count))
+        .collect()
+}
+
+/// Peak-to-RMS ratio, about 1.414 for a sine. Returns 0.0 for an empty or silent signal.
+pub fn crest_factor(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+
+    let peak = samples.iter().fold(0f64, |peak, x| peak.max(x.abs()));
+    let rms = (samples.iter().map(|x| x * x).sum::<f64>() / samples.len() as f64).sqrt();
+    if rms > 0.0 {
+        peak / rms
+    } else {
+        0.0
+    }
+}
+
+/// Signal-to-noise ratio in dB of `test` against `reference`, taking
+/// `reference - test` as the noise. Identical buffers give infinity.
+///
+/// Errors if the lengths differ.
+pub fn snr(reference: &[f64], test: &[f64]) -> Result<f64> {
+    ensure!(
+        reference.len() == test.len(),
+        "Reference and test must have the same length, got {} and {}.",
+        reference.len(),
+        test.len()
+    );
+
+    let signal_power: f64 = reference.iter().map(|x| x * x).sum();
+    let noise_power: f64 = reference.iter().zip(test).map(|(r, t)| (r - t) * (r - t)).sum();
+    if noise_power == 0.0 {
+        return Ok(f64::INFINITY);
+    }
+
+    Ok(power_to_db(signal_power, noise_power))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, NoiseGen, SineGen};
+
+    #[test]
+    fn test_clipping_stats() {
+        let mut signal = vec![0.5; 100];
+        signal[10..15].fill(1.0);
+        signal[40] = -1.0;
+        signal[70..73].fill(0.99);
+
+        let stats = clipping_stats(&signal, 0.99);
+        assert_eq!(stats.count, 9);
+        assert_eq!(stats.runs, 3);
+        assert!((stats.ratio - 0.09).abs() < 1e-12);
+
+        assert_eq!(clipping_stats(&signal, 1.0).runs, 2);
+        assert_eq!(clipping_stats(&[], 1.0), ClipStats { count: 0, ratio: 0.0, runs: 0 });
+    }
+
+    #[test]
+    fn test_crest_factor() {
+        let sine = SineGen::new(100.0).render(1.0, 8000.0);
+        assert!((crest_factor(&sine) - 2f64.sqrt()).abs() < 1e-3);
+
+        // Uniform noise has an RMS of peak / sqrt(3).
+        let noise = NoiseGen::new(5).render(1.0, 48000.0);
+        assert!((crest_factor(&noise) - 3f64.sqrt()).abs() < 0.02);
+
+        assert_eq!(crest_factor(&[]), 0.0);
+        assert_eq!(crest_factor(&[0.0; 10]), 0.0);
+    }
+
+    #[test]
+    fn test_amplitude_histogram() {
+        let sine = SineGen::new(100.0).render(1.0, 8000.0);
+        let histogram = amplitude_histogram(&sine, 10);
+
+        assert_eq!(histogram.len(), 10);
+        assert_eq!(histogram.iter().map(|&(_, count)| count).sum::<usize>(), sine.len());
+        // A sine spends most of its time near its peaks.
+        let (edge, middle) = (histogram[0].1, histogram[5].1);
+        assert!(edge > 2 * middle);
+        assert!(histogram[9].1 > 2 * middle);
+
+        assert_eq!(amplitude_histogram(&[0.5; 4], 3), vec![(0.5, 4), (0.5, 0), (0.5, 0)]);
+        assert!(amplitude_histogram(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_snr() -> Result<()> {
+        // Signal power 2, noise power 0.01.
+        assert!((snr(&[1.0, 1.0], &[1.0, 0.9])? - 23.0103).abs() < 1e-4);
+        assert_eq!(snr(&[0.5, -0.5], &[0.5, -0.5])?, f64::INFINITY);
+        assert!(snr(&[1.0, 1.0], &[1.0]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+pub mod cepstrum;
+pub mod channels;
+pub mod denoise;
+pub mod effects;
+pub mod envelope;
+pub mod features;
+pub mod filter;
+pub mod gate;
+pub mod generator;
+pub mod goertzel;
+pub mod io;
+pub mod level;
+pub mod mock;
+pub mod music;
+pub mod preprocess;
+pub mod spectrum;
+pub mod units;
+pub mod util;
+pub mod window;
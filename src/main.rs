@@ -1,6 +1,6 @@
 use anyhow::{Result, anyhow};
 use cpal::{
-    traits::{DeviceTrait, HostTrait, StreamTrait}, Device, FromSample, Sample, SizedSample
+    traits::{DeviceTrait, HostTrait}, Device
 };
 
 fn get_default_device_config() -> Result<Device> {
@@ -0,0 +1,216 @@
+use std::f64::consts::PI;
+
+use anyhow::{ensure, Result};
+
+/// Additively synthesize `duration` seconds of `Σ amplitude(k)·sin(2π·k·f·t)` over
+/// the harmonics `k` below Nyquist. Harmonics with zero amplitude are skipped.
+///
+/// Panics if `frequency` is not positive, since the harmonics would never pass Nyquist.
+fn additive(frequency: f64, duration: f64, sample_rate: f64, amplitude: impl Fn(usize) -> f64) -> Vec<f64> {
+    assert!(frequency > 0.0, "Frequency must be positive, got {}.", frequency);
+
+    let len = (duration * sample_rate) as usize;
+    let harmonics: Vec<(f64, f64)> = (1..)
+        .map(|k| (k, k as f64 * frequency))
+        .take_while(|&(_, f)| f < sample_rate / 2.0)
+        .map(|(k, f)| (f, amplitude(k)))
+        .filter(|&(_, a)| a != 0.0)
+        .collect();
+
+    (0..len)
+        .map(|i| {
+            let t = i as f64 / sample_rate;
+            harmonics.iter().map(|(f, a)| a * (2.0 * PI * f * t).sin()).sum()
+        })
+        .collect()
+}
+
+/// Square wave built only from odd harmonics below Nyquist, so it doesn't alias.
+///
+/// Panics if `frequency` is not positive.
+pub fn mock_square_bandlimited(frequency: f64, duration: f64, sample_rate: f64) -> Vec<f64> {
+    additive(frequency, duration, sample_rate, |k| {
+        if k % 2 == 1 {
+            4.0 / (PI * k as f64)
+        } else {
+            0.0
+        }
+    })
+}
+
+/// Rising sawtooth built only from harmonics below Nyquist, so it doesn't alias.
+///
+/// Panics if `frequency` is not positive.
+pub fn mock_sawtooth_bandlimited(frequency: f64, duration: f64, sample_rate: f64) -> Vec<f64> {
+    additive(frequency, duration, sample_rate, |k| {
+        let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+        sign * 2.0 / (PI * k as f64)
+    })
+}
+
+/// Sum of sines, where component `i` has frequency `frequency_list[i]`, phase
+/// `phase_list[i]` (radians) and amplitude `amplitude_list[i]`.
+///
+/// Errors if the three lists differ in length.
+pub fn mock_sine_with_amplitudes(
+    frequency_list: &[f64],
+    phase_list: &[f64],
+    amplitude_list: &[f64],
+    duration: f64,
+    sample_rate: f64,
+) -> Result<Vec<f64>> {
+    ensure!(
+        frequency_list.len() == phase_list.len() && frequency_list.len() == amplitude_list.len(),
+        "Frequency, phase and amplitude lists must have the same length, got {}, {} and {}.",
+        frequency_list.len(),
+        phase_list.len(),
+        amplitude_list.len()
+    );
+
+    let len = (duration * sample_rate) as usize;
+    Ok((0..len)
+        .map(|i| {
+            let t = i as f64 / sample_rate;
+            frequency_list
+                .iter()
+                .zip(phase_list)
+                .zip(amplitude_list)
+                .map(|((f, phase), a)| a * (2.0 * PI * f * t + phase).sin())
+                .sum()
+        })
+        .collect())
+}
+
+/// Multiply `samples` by a linear ADSR envelope.
+///
+/// The level ramps from 0 to 1 over `attack_s`, falls to `sustain_level` over
+/// `decay_s`, holds there, and reaches 0 on the last sample after `release_s`.
+/// If the buffer is too short, the release starts early, from whatever level
+/// the envelope had reached.
+pub fn apply_adsr(samples: &mut [f64], attack_s: f64, decay_s: f64, sustain_level: f64, release_s: f64, sample_rate: f64) {
+    let attack = attack_s * sample_rate;
+    let decay = decay_s * sample_rate;
+    let release = ((release_s * sample_rate) as usize).min(samples.len());
+    let release_start = samples.len() - release;
+
+    let level = |i: usize| {
+        let i = i as f64;
+        if i < attack {
+            i / attack
+        } else if i < attack + decay {
+            1.0 - (1.0 - sustain_level) * (i - attack) / decay
+        } else {
+            sustain_level
+        }
+    };
+
+    let release_level = level(release_start);
+    for (i, sample) in samples.iter_mut().enumerate() {
+        let gain = if i < release_start {
+            level(i)
+        } else {
+            release_level * (1.0 - (i - release_start + 1) as f64 / release as f64)
+        };
+        *sample *= gain;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generator::{Generator, SquareGen},
+        goertzel::goertzel_power,
+    };
+
+    const SAMPLE_RATE: f64 = 44100.0;
+
+    /// Where the 23rd..29th harmonics of a 1 kHz tone fold back to below Nyquist.
+    const ALIASES: [f64; 4] = [21100.0, 19100.0, 17100.0, 15100.0];
+
+    fn max_alias_ratio(signal: &[f64]) -> f64 {
+        let fundamental = goertzel_power(signal, 1000.0, SAMPLE_RATE);
+        ALIASES
+            .iter()
+            .map(|&f| goertzel_power(signal, f, SAMPLE_RATE) / fundamental)
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_square_bandlimited_has_no_aliases() {
+        let square = mock_square_bandlimited(1000.0, 1.0, SAMPLE_RATE);
+        let third = goertzel_power(&square, 3000.0, SAMPLE_RATE) / goertzel_power(&square, 1000.0, SAMPLE_RATE);
+
+        assert!((third - 1.0 / 9.0).abs() < 1e-6);
+        assert!(max_alias_ratio(&square) < 1e-12);
+        // The naive square wave does fold its upper harmonics back.
+        assert!(max_alias_ratio(&SquareGen::new(1000.0).render(1.0, SAMPLE_RATE)) > 1e-4);
+    }
+
+    #[test]
+    fn test_sawtooth_bandlimited_has_no_aliases() {
+        let sawtooth = mock_sawtooth_bandlimited(1000.0, 1.0, SAMPLE_RATE);
+        let second = goertzel_power(&sawtooth, 2000.0, SAMPLE_RATE) / goertzel_power(&sawtooth, 1000.0, SAMPLE_RATE);
+
+        assert!((second - 0.25).abs() < 1e-6);
+        assert!(max_alias_ratio(&sawtooth) < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Frequency must be positive")]
+    fn test_bandlimited_zero_frequency() {
+        mock_square_bandlimited(0.0, 1.0, SAMPLE_RATE);
+    }
+
+    #[test]
+    fn test_sine_with_amplitudes() -> Result<()> {
+        let signal = mock_sine_with_amplitudes(&[1000.0, 3000.0], &[0.0, PI / 2.0], &[1.0, 0.25], 0.01, SAMPLE_RATE)?;
+        assert_eq!(signal.len(), 441);
+        for (i, x) in signal.iter().enumerate() {
+            let t = i as f64 / SAMPLE_RATE;
+            let expected = (2.0 * PI * 1000.0 * t).sin() + 0.25 * (2.0 * PI * 3000.0 * t).cos();
+            assert!((x - expected).abs() < 1e-12);
+        }
+
+        assert!(mock_sine_with_amplitudes(&[1000.0], &[0.0, 0.0], &[1.0], 0.01, SAMPLE_RATE).is_err());
+        assert!(mock_sine_with_amplitudes(&[1000.0], &[0.0], &[], 0.01, SAMPLE_RATE).is_err());
+        Ok(())
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-3, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_apply_adsr() {
+        // At 10 Hz: 2 samples of attack, 2 of decay and 3 of release.
+        let mut samples = [1.0; 10];
+        apply_adsr(&mut samples, 0.2, 0.2, 0.5, 0.3, 10.0);
+        assert_close(&samples, &[0.0, 0.5, 1.0, 0.75, 0.5, 0.5, 0.5, 0.333, 0.167, 0.0]);
+    }
+
+    #[test]
+    fn test_apply_adsr_zero_times() {
+        let mut samples = [1.0, -1.0, 0.5, 1.0];
+        apply_adsr(&mut samples, 0.0, 0.0, 0.8, 0.0, 10.0);
+        assert_eq!(samples, [0.8, -0.8, 0.4, 0.8]);
+    }
+
+    #[test]
+    fn test_apply_adsr_short_buffer() {
+        // The 6-sample release covers the whole buffer and starts from the sustain level.
+        let mut samples = [1.0; 4];
+        apply_adsr(&mut samples, 0.0, 0.0, 0.8, 0.6, 10.0);
+        assert_close(&samples, &[0.6, 0.4, 0.2, 0.0]);
+
+        // With an attack, the release starts from silence.
+        let mut samples = [1.0; 4];
+        apply_adsr(&mut samples, 0.2, 0.2, 0.8, 0.6, 10.0);
+        assert_eq!(samples, [0.0; 4]);
+
+        apply_adsr(&mut [], 0.2, 0.2, 0.8, 0.6, 10.0);
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{anyhow, ensure, Result};
+
+/// Reference pitch of A4 in Hz.
+pub const A4_FREQ: f64 = 440.0;
+const A4_MIDI: i32 = 69;
+
+const NOTE_NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+
+/// Nearest equal-tempered note to `freq`, as `(name, octave, cents)`, where
+/// `cents` is how far `freq` sits above the note.
+///
+/// Errors if `freq` is not a positive, finite number.
+pub fn freq_to_note(freq: f64) -> Result<(String, i32, f64)> {
+    ensure!(freq > 0.0 && freq.is_finite(), "Frequency must be positive and finite, got {}.", freq);
+
+    let midi = A4_MIDI as f64 + 12.0 * (freq / A4_FREQ).log2();
+    let nearest = midi.round();
+    let cents = (midi - nearest) * 100.0;
+
+    let nearest = nearest as i32;
+    let name = NOTE_NAMES[nearest.rem_euclid(12) as usize].to_string();
+    let octave = nearest.div_euclid(12) - 1;
+
+    Ok((name, octave, cents))
+}
+
+/// Frequency of a note such as `"A"`, `"C#"` or `"Bb"` in the given octave.
+pub fn note_to_freq(name: &str, octave: i32) -> Result<f64> {
+    let mut chars = name.chars();
+    let letter = chars.next().unwrap_or_default().to_ascii_uppercase();
+    let base = NOTE_NAMES
+        .iter()
+        .position(|n| n.len() == 1 && n.starts_with(letter))
+        .ok_or(anyhow!("Unknown note name '{}'.", name))? as i32;
+    let offset = match chars.as_str() {
+        "" => 0,
+        "#" => 1,
+        "b" => -1,
+        _ => return Err(anyhow!("Unknown note name '{}'.", name)),
+    };
+
+    let midi = (octave + 1) * 12 + base + offset;
+    Ok(A4_FREQ * 2f64.powf((midi - A4_MIDI) as f64 / 12.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freq_to_note() -> Result<()> {
+        let (name, octave, cents) = freq_to_note(440.0)?;
+        assert_eq!((name.as_str(), octave), ("A", 4));
+        assert!(cents.abs() < 1e-9);
+
+        let (name, octave, cents) = freq_to_note(466.16)?;
+        assert_eq!((name.as_str(), octave), ("A#", 4));
+        assert!(cents.abs() < 0.1);
+
+        let (name, octave, _) = freq_to_note(261.63)?;
+        assert_eq!((name.as_str(), octave), ("C", 4));
+
+        // A quarter tone above A4.
+        let (name, octave, cents) = freq_to_note(440.0 * 2f64.powf(0.4 / 12.0))?;
+        assert_eq!((name.as_str(), octave), ("A", 4));
+        assert!((cents - 40.0).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_note_to_freq() {
+        assert!((note_to_freq("A", 4).unwrap() - 440.0).abs() < 1e-9);
+        assert!((note_to_freq("a", 5).unwrap() - 880.0).abs() < 1e-9);
+        assert!((note_to_freq("C", 4).unwrap() - 261.6255653).abs() < 1e-6);
+        assert!((note_to_freq("A#", 4).unwrap() - 466.1637615).abs() < 1e-6);
+        assert_eq!(note_to_freq("Bb", 4).unwrap(), note_to_freq("A#", 4).unwrap());
+        assert_eq!(note_to_freq("Cb", 5).unwrap(), note_to_freq("B", 4).unwrap());
+    }
+
+    #[test]
+    fn test_note_to_freq_errors() {
+        assert!(note_to_freq("", 4).is_err());
+        assert!(note_to_freq("H", 4).is_err());
+        assert!(note_to_freq("C##", 4).is_err());
+        assert!(note_to_freq("C#", 4).is_ok());
+        assert!(note_to_freq("é", 4).is_err());
+
+        assert!(freq_to_note(0.0).is_err());
+        assert!(freq_to_note(-440.0).is_err());
+        assert!(freq_to_note(f64::NAN).is_err());
+        assert!(freq_to_note(f64::INFINITY).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+/// Subtract the mean from every sample, leaving `signal` with zero DC offset.
+pub fn remove_dc_in_place(signal: &mut [f64]) {
+    if signal.is_empty() {
+        return;
+    }
+
+    let mean = signal.iter().sum::<f64>() / signal.len() as f64;
+    for sample in signal.iter_mut() {
+        *sample -= mean;
+    }
+}
+
+/// Copy of `signal` with its mean subtracted.
+pub fn remove_dc(signal: &[f64]) -> Vec<f64> {
+    let mut output = signal.to_vec();
+    remove_dc_in_place(&mut output);
+    output
+}
+
+/// Scale `samples` so the largest absolute value is 1.0. A silent buffer is left as is.
+pub fn normalize_peak(samples: &mut [f64]) {
+    let peak = samples.iter().fold(0f64, |peak, x| peak.max(x.abs()));
+    if peak == 0.0 {
+        return;
+    }
+
+    for sample in samples.iter_mut() {
+        *sample /= peak;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generator::{Generator, SineGen},
+        util::bin_aligned_frequency,
+    };
+    use std::f64::consts::PI;
+
+    const SAMPLE_RATE: f64 = 44100.0;
+    const N: usize = 1024;
+
+    /// Power of the DFT bin centred on `freq`.
+    fn bin_power(samples: &[f64], freq: f64) -> f64 {
+        let (re, im) = samples.iter().enumerate().fold((0.0, 0.0), |(re, im), (n, x)| {
+            let phase = -2.0 * PI * freq * n as f64 / SAMPLE_RATE;
+            (re + x * phase.cos(), im + x * phase.sin())
+        });
+        re * re + im * im
+    }
+
+    #[test]
+    fn test_remove_dc() {
+        let freq = bin_aligned_frequency(1000.0, N, SAMPLE_RATE);
+        let tone = SineGen::new(freq).render(N as f64 / SAMPLE_RATE, SAMPLE_RATE);
+        let signal: Vec<f64> = tone.iter().map(|x| x + 0.3).collect();
+
+        let output = remove_dc(&signal);
+        assert!(bin_power(&signal, 0.0) > 1e4);
+        assert!(bin_power(&output, 0.0) < 1e-12);
+        let tone_power = bin_power(&tone, freq);
+        assert!((bin_power(&output, freq) - tone_power).abs() < 1e-9 * tone_power);
+
+        let mut in_place = signal.clone();
+        remove_dc_in_place(&mut in_place);
+        assert_eq!(in_place, output);
+    }
+
+    #[test]
+    fn test_remove_dc_empty() {
+        assert!(remove_dc(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_normalize_peak() {
+        let mut samples = [0.2, -0.8, 0.4];
+        normalize_peak(&mut samples);
+        assert_eq!(samples, [0.25, -1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_normalize_peak_silent() {
+        let mut samples = [0.0; 4];
+        normalize_peak(&mut samples);
+        assert_eq!(samples, [0.0; 4]);
+    }
+}
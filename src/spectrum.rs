@@ -0,0 +1,219 @@
+use std::collections::VecDeque;
+
+/// Resample a magnitude spectrum onto `points` log-spaced frequencies from
+/// `f_min` to `f_max`, so every octave gets the same number of points on a chart.
+///
+/// `spectrum` must be sorted by frequency. Magnitudes are interpolated linearly
+/// between neighbouring bins, and points outside the spectrum take the nearest
+/// edge value.
+///
+/// Panics unless both `f_min` and `f_max` are positive, since a log axis can't reach 0 Hz.
+pub fn resample_log_frequency(spectrum: &[(f64, f64)], points: usize, f_min: f64, f_max: f64) -> Vec<(f64, f64)> {
+    assert!(
+        f_min > 0.0 && f_max > 0.0,
+        "Log frequency range must be positive, got {}..{}.",
+        f_min,
+        f_max
+    );
+
+    if spectrum.is_empty() || points == 0 {
+        return Vec::new();
+    }
+
+    let ratio = f_max / f_min;
+    let steps = points.saturating_sub(1).max(1) as f64;
+    (0..points)
+        .map(|i| {
+            let freq = f_min * ratio.powf(i as f64 / steps);
+            let upper = spectrum.partition_point(|&(f, _)| f < freq);
+            let magnitude = if upper == 0 {
+                spectrum[0].1
+            } else if upper == spectrum.len() {
+                spectrum[upper - 1].1
+            } else {
+                let (f0, m0) = spectrum[upper - 1];
+                let (f1, m1) = spectrum[upper];
+                m0 + (m1 - m0) * (freq - f0) / (f1 - f0)
+            };
+            (freq, magnitude)
+        })
+        .collect()
+}
+
+/// Temporal smoothing applied by [`SpectrumAverager`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AveragingMode {
+    /// `out = alpha * out + (1 - alpha) * in`.
+    Exponential(f64),
+    /// Maximum seen so far in each bin.
+    PeakHold,
+    /// Mean of the last `n` frames.
+    LinearAverage(usize),
+}
+
+/// Smooths successive magnitude spectra for a steady analyzer display.
+///
+/// The first frame is passed through unchanged. A frame with a different number
+/// of bins resets the state, e.g. after the FFT size changes.
+#[derive(Debug, Clone)]
+pub struct SpectrumAverager {
+    mode: AveragingMode,
+    output: Vec<f64>,
+    history: VecDeque<Vec<f64>>,
+    sum: Vec<f64>,
+}
+
+impl SpectrumAverager {
+    pub fn new(mode: AveragingMode) -> Self {
+        SpectrumAverager {
+            mode,
+            output: Vec::new(),
+            history: VecDeque::new(),
+            sum: Vec::new(),
+        }
+    }
+
+    pub fn mode(&self) -> AveragingMode {
+        self.mode
+    }
+
+    /// The smoothed spectrum after the latest update.
+    pub fn output(&self) -> &[f64] {
+        &self.output
+    }
+
+    pub fn reset(&mut self) {
+        self.output.clear();
+        self.history.clear();
+        self.sum.clear();
+    }
+
+    pub fn update(&mut self, magnitudes: &[f64]) -> &[f64] {
+        if magnitudes.len() != self.output.len() {
+            self.reset();
+        }
+        let first = self.output.is_empty();
+
+        match self.mode {
+            AveragingMode::Exponential(alpha) => {
+                if first {
+                    self.output = magnitudes.to_vec();
+                } else {
+                    for (out, &m) in self.output.iter_mut().zip(magnitudes) {
+                        *out = alpha * *out + (1.0 - alpha) * m;
+                    }
+                }
+            }
+            AveragingMode::PeakHold => {
+                if first {
+                    self.output = magnitudes.to_vec();
+                } else {
+                    for (out, &m) in self.output.iter_mut().zip(magnitudes) {
+                        *out = out.max(m);
+                    }
+                }
+            }
+            AveragingMode::LinearAverage(n) => {
+                if first {
+                    self.sum = vec![0.0; magnitudes.len()];
+                }
+                for (total, &m) in self.sum.iter_mut().zip(magnitudes) {
+                    *total += m;
+                }
+                self.history.push_back(magnitudes.to_vec());
+                while self.history.len() > n.max(1) {
+                    if let Some(oldest) = self.history.pop_front() {
+                        for (total, m) in self.sum.iter_mut().zip(oldest) {
+                            *total -= m;
+                        }
+                    }
+                }
+                let count = self.history.len() as f64;
+                self.output = self.sum.iter().map(|total| total / count).collect();
+            }
+        }
+
+        &self.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear_spectrum() -> Vec<(f64, f64)> {
+        (0..1000).map(|i| (i as f64 * 10.0, i as f64)).collect()
+    }
+
+    #[test]
+    fn test_resample_log_frequency() {
+        let resampled = resample_log_frequency(&linear_spectrum(), 31, 20.0, 20000.0);
+
+        assert_eq!(resampled.len(), 31);
+        assert!((resampled[0].0 - 20.0).abs() < 1e-9);
+        assert!((resampled[30].0 - 20000.0).abs() < 1e-6);
+        // Every step covers a tenth of a decade.
+        for pair in resampled.windows(2) {
+            assert!((pair[1].0 / pair[0].0 - 10f64.powf(0.1)).abs() < 1e-9);
+        }
+        // Interpolated inside the spectrum, clamped past its last bin at 9990 Hz.
+        assert!((resampled[0].1 - 2.0).abs() < 1e-9);
+        assert_eq!(resampled[30].1, 999.0);
+    }
+
+    #[test]
+    fn test_resample_log_frequency_empty() {
+        assert!(resample_log_frequency(&[], 10, 20.0, 20000.0).is_empty());
+        assert!(resample_log_frequency(&linear_spectrum(), 0, 20.0, 20000.0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Log frequency range must be positive")]
+    fn test_resample_log_frequency_zero_min() {
+        resample_log_frequency(&linear_spectrum(), 10, 0.0, 20000.0);
+    }
+
+    #[test]
+    fn test_exponential_averaging() {
+        let mut averager = SpectrumAverager::new(AveragingMode::Exponential(0.5));
+        assert_eq!(averager.update(&[2.0, 4.0]), [2.0, 4.0]);
+        assert_eq!(averager.update(&[0.0, 0.0]), [1.0, 2.0]);
+        assert_eq!(averager.update(&[0.0, 0.0]), [0.5, 1.0]);
+        assert_eq!(averager.output(), [0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_peak_hold() {
+        let mut averager = SpectrumAverager::new(AveragingMode::PeakHold);
+        assert_eq!(averager.update(&[1.0, 5.0]), [1.0, 5.0]);
+        assert_eq!(averager.update(&[3.0, 2.0]), [3.0, 5.0]);
+        assert_eq!(averager.update(&[0.0, 0.0]), [3.0, 5.0]);
+    }
+
+    #[test]
+    fn test_linear_average() {
+        let mut averager = SpectrumAverager::new(AveragingMode::LinearAverage(2));
+        assert_eq!(averager.update(&[1.0, 2.0]), [1.0, 2.0]);
+        assert_eq!(averager.update(&[3.0, 4.0]), [2.0, 3.0]);
+        // The first frame drops out of the window.
+        assert_eq!(averager.update(&[5.0, 6.0]), [4.0, 5.0]);
+
+        // A zero-length window averages over the latest frame only.
+        let mut averager = SpectrumAverager::new(AveragingMode::LinearAverage(0));
+        assert_eq!(averager.update(&[1.0, 2.0]), [1.0, 2.0]);
+        assert_eq!(averager.update(&[3.0, 4.0]), [3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_bin_count_change_resets() {
+        let mut averager = SpectrumAverager::new(AveragingMode::LinearAverage(4));
+        averager.update(&[1.0, 1.0]);
+        averager.update(&[3.0, 3.0]);
+        assert_eq!(averager.update(&[4.0, 4.0, 4.0]), [4.0, 4.0, 4.0]);
+        assert_eq!(averager.update(&[2.0, 2.0, 2.0]), [3.0, 3.0, 3.0]);
+
+        averager.reset();
+        assert!(averager.output().is_empty());
+        assert_eq!(averager.update(&[1.0, 1.0, 1.0]), [1.0, 1.0, 1.0]);
+    }
+}
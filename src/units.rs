@@ -0,0 +1,51 @@
+/// Lowest level, in dB, returned by the conversions. Zero or tiny inputs come out
+/// here instead of `-inf`.
+pub const DB_FLOOR: f64 = -200.0;
+
+/// `20 * log10(|amp| / reference)`, floored at [`DB_FLOOR`].
+pub fn amplitude_to_db(amp: f64, reference: f64) -> f64 {
+    (20.0 * (amp.abs() / reference).log10()).max(DB_FLOOR)
+}
+
+pub fn db_to_amplitude(db: f64, reference: f64) -> f64 {
+    reference * 10f64.powf(db / 20.0)
+}
+
+/// `10 * log10(power / reference)`, floored at [`DB_FLOOR`].
+pub fn power_to_db(power: f64, reference: f64) -> f64 {
+    (10.0 * (power.abs() / reference).log10()).max(DB_FLOOR)
+}
+
+pub fn db_to_power(db: f64, reference: f64) -> f64 {
+    reference * 10f64.powf(db / 10.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floor_at_zero() {
+        assert_eq!(amplitude_to_db(0.0, 1.0), DB_FLOOR);
+        assert_eq!(power_to_db(0.0, 1.0), DB_FLOOR);
+    }
+
+    #[test]
+    fn test_known_values() {
+        assert!((amplitude_to_db(10.0, 1.0) - 20.0).abs() < 1e-12);
+        assert!((amplitude_to_db(-0.5, 0.5)).abs() < 1e-12);
+        assert!((power_to_db(100.0, 1.0) - 20.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for &value in &[1e-6, 0.01, 0.5, 1.0, 3.0, 1000.0] {
+            for &reference in &[1.0, 2e-5, 0.775] {
+                let amp = db_to_amplitude(amplitude_to_db(value, reference), reference);
+                let power = db_to_power(power_to_db(value, reference), reference);
+                assert!((amp - value).abs() < 1e-9 * value);
+                assert!((power - value).abs() < 1e-9 * value);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+/// Snap `desired_hz` to the centre of the nearest bin of an `n`-point transform at
+/// `sample_rate`. A tone at the result completes a whole number of cycles in `n`
+/// samples, so its spectrum shows no leakage.
+///
+/// Panics if `n` is 0.
+pub fn bin_aligned_frequency(desired_hz: f64, n: usize, sample_rate: f64) -> f64 {
+    assert!(n > 0, "Transform size must be positive.");
+
+    let bin_width = sample_rate / n as f64;
+    (desired_hz / bin_width).round() * bin_width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{Generator, SineGen};
+    use std::f64::consts::PI;
+
+    const SAMPLE_RATE: f64 = 44100.0;
+    const N: usize = 1024;
+
+    /// Power of the DFT bin centred on `freq`.
+    fn bin_power(samples: &[f64], freq: f64) -> f64 {
+        let (re, im) = samples.iter().enumerate().fold((0.0, 0.0), |(re, im), (n, x)| {
+            let phase = -2.0 * PI * freq * n as f64 / SAMPLE_RATE;
+            (re + x * phase.cos(), im + x * phase.sin())
+        });
+        re * re + im * im
+    }
+
+    #[test]
+    fn test_bin_aligned_frequency() {
+        let bin_width = SAMPLE_RATE / N as f64;
+        assert_eq!(bin_aligned_frequency(1000.0, N, SAMPLE_RATE), 23.0 * bin_width);
+        assert_eq!(bin_aligned_frequency(0.0, N, SAMPLE_RATE), 0.0);
+    }
+
+    #[test]
+    fn test_bin_aligned_frequency_has_no_leakage() {
+        let bin_width = SAMPLE_RATE / N as f64;
+        let freq = bin_aligned_frequency(1000.0, N, SAMPLE_RATE);
+        let samples = SineGen::new(freq).render(N as f64 / SAMPLE_RATE, SAMPLE_RATE);
+        assert_eq!(samples.len(), N);
+
+        let peak = bin_power(&samples, freq);
+        assert!((peak - (N * N / 4) as f64).abs() < 1e-6 * peak);
+        for offset in [-3.0, -2.0, -1.0, 1.0, 2.0, 3.0] {
+            assert!(bin_power(&samples, freq + offset * bin_width) < 1e-12 * peak);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bin_aligned_frequency_zero_size() {
+        bin_aligned_frequency(1000.0, 0, SAMPLE_RATE);
+    }
+}
@@ -0,0 +1,215 @@
+use std::f64::consts::PI;
+
+/// Dolph-Chebyshev window, whose sidelobes all sit `attenuation_db` below the main lobe.
+///
+/// Computed like SciPy's `windows.chebwin`: sample the Chebyshev polynomial
+/// frequency response, take its inverse DFT and normalize the peak to 1.0.
+pub fn dolph_chebyshev(size: usize, attenuation_db: f64) -> Vec<f64> {
+    if size <= 1 {
+        return vec![1.0; size];
+    }
+
+    let m = size as f64;
+    let order = m - 1.0;
+    let beta = ((10f64.powf(attenuation_db.abs() / 20.0)).acosh() / order).cosh();
+
+    // Chebyshev polynomial of degree `order`, evaluated at beta * cos(pi * k / M).
+    let response: Vec<f64> = (0..size)
+        .map(|k| {
+            let x = beta * (PI * k as f64 / m).cos();
+            if x > 1.0 {
+                (order * x.acosh()).cosh()
+            } else if x < -1.0 {
+                let sign = if size % 2 == 1 { 1.0 } else { -1.0 };
+                sign * (order * (-x).acosh()).cosh()
+            } else {
+                (order * x.acos()).cos()
+            }
+        })
+        .collect();
+
+    // Real part of the DFT. For even sizes the response is first shifted by half a bin.
+    let shift = if size % 2 == 1 { 0.0 } else { 0.5 };
+    let dft = |j: usize| -> f64 {
+        response
+            .iter()
+            .enumerate()
+            .map(|(k, p)| p * (2.0 * PI * k as f64 * (shift - j as f64) / m).cos())
+            .sum()
+    };
+
+    let half = size / 2 + 1;
+    let right: Vec<f64> = (0..half).map(dft).collect();
+    let mut window: Vec<f64> = right[1..].iter().rev().copied().collect();
+    if size % 2 == 1 {
+        window.extend_from_slice(&right);
+    } else {
+        window.extend_from_slice(&right[1..]);
+    }
+
+    let max = window.iter().copied().fold(f64::MIN, f64::max);
+    window.iter().map(|w| w / max).collect()
+}
+
+/// Tukey (tapered cosine) window: flat at exactly 1.0 in the middle, with cosine
+/// tapers covering a total fraction `alpha` of the window. `alpha` is clamped to
+/// `[0, 1]`; 0 gives a rectangular window and 1 a Hann window.
+pub fn tukey(size: usize, alpha: f64) -> Vec<f64> {
+    if size <= 1 {
+        return vec![1.0; size];
+    }
+
+    let alpha = alpha.clamp(0.0, 1.0);
+    let last = (size - 1) as f64;
+    (0..size)
+        .map(|n| {
+            let x = n as f64 / last;
+            if x < alpha / 2.0 {
+                0.5 * (1.0 + (PI * (2.0 * x / alpha - 1.0)).cos())
+            } else if x > 1.0 - alpha / 2.0 {
+                0.5 * (1.0 + (PI * (2.0 * (x - 1.0) / alpha + 1.0)).cos())
+            } else {
+                1.0
+            }
+        })
+        .collect()
+}
+
+/// Gaussian window with standard deviation `std`, in samples, like SciPy's `windows.gaussian`.
+///
+/// Panics if `std` is not positive.
+pub fn gaussian(size: usize, std: f64) -> Vec<f64> {
+    assert!(std > 0.0, "Gaussian window standard deviation must be positive, got {}.", std);
+
+    let center = (size as f64 - 1.0) / 2.0;
+    (0..size)
+        .map(|n| {
+            let x = (n as f64 - center) / std;
+            (-0.5 * x * x).exp()
+        })
+        .collect()
+}
+
+/// 4-term Nuttall window (minimum 4-term Blackman-Harris), like SciPy's `windows.nuttall`.
+pub fn nuttall(size: usize) -> Vec<f64> {
+    const A: [f64; 4] = [0.3635819, 0.4891775, 0.1365995, 0.0106411];
+
+    if size <= 1 {
+        return vec![1.0; size];
+    }
+
+    let last = (size - 1) as f64;
+    (0..size)
+        .map(|n| {
+            let phase = 2.0 * PI * n as f64 / last;
+            A[0] - A[1] * phase.cos() + A[2] * (2.0 * phase).cos() - A[3] * (3.0 * phase).cos()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f64], expected: &[f64], tolerance: f64) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < tolerance, "{} != {}", a, e);
+        }
+    }
+
+    #[test]
+    fn test_tukey_endpoints() {
+        let size = 16;
+        assert_eq!(tukey(size, 0.0), vec![1.0; size]);
+
+        let hann: Vec<f64> = (0..size)
+            .map(|n| 0.5 - 0.5 * (2.0 * PI * n as f64 / (size - 1) as f64).cos())
+            .collect();
+        assert_close(&tukey(size, 1.0), &hann, 1e-12);
+    }
+
+    #[test]
+    fn test_tukey_flat_centre() {
+        let window = tukey(16, 0.5);
+        assert_eq!(window[0], 0.0);
+        assert_eq!(window[15], 0.0);
+        assert!(window[4..12].iter().all(|&w| w == 1.0));
+        assert_close(&window[..4], &[0.0, 0.16543469682057088, 0.5522642316338268, 0.9045084971874737], 1e-12);
+    }
+
+    #[test]
+    fn test_gaussian() {
+        // scipy.signal.windows.gaussian(7, 1.5)
+        let expected = [
+            0.1353352832366127,
+            0.41111229050718745,
+            0.8007374029168081,
+            1.0,
+            0.8007374029168081,
+            0.41111229050718745,
+            0.1353352832366127,
+        ];
+        assert_close(&gaussian(7, 1.5), &expected, 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gaussian_zero_std() {
+        gaussian(7, 0.0);
+    }
+
+    #[test]
+    fn test_nuttall() {
+        // scipy.signal.windows.nuttall(8)
+        let expected = [
+            0.0003628000000000381,
+            0.03777576895352028,
+            0.34272761996881956,
+            0.8918518610776603,
+            0.8918518610776603,
+            0.34272761996881956,
+            0.03777576895352028,
+            0.0003628000000000381,
+        ];
+        assert_close(&nuttall(8), &expected, 1e-12);
+    }
+
+    /// Peak sidelobe level of `window`'s spectrum relative to its main lobe, in dB.
+    fn peak_sidelobe_db(window: &[f64]) -> f64 {
+        let bins = 4096;
+        let magnitude: Vec<f64> = (0..bins / 2)
+            .map(|k| {
+                let (re, im) = window.iter().enumerate().fold((0.0, 0.0), |(re, im), (n, w)| {
+                    let phase = -2.0 * PI * (k * n) as f64 / bins as f64;
+                    (re + w * phase.cos(), im + w * phase.sin())
+                });
+                (re * re + im * im).sqrt()
+            })
+            .collect();
+
+        let mut main_lobe_end = 1;
+        while magnitude[main_lobe_end] < magnitude[main_lobe_end - 1] {
+            main_lobe_end += 1;
+        }
+        let sidelobe = magnitude[main_lobe_end..].iter().copied().fold(0.0, f64::max);
+        20.0 * (sidelobe / magnitude[0]).log10()
+    }
+
+    #[test]
+    fn test_dolph_chebyshev_sidelobes() {
+        for size in [31, 32] {
+            let window = dolph_chebyshev(size, 100.0);
+            assert_eq!(window.len(), size);
+            assert_close(&window, &window.iter().rev().copied().collect::<Vec<_>>(), 1e-12);
+            assert!((peak_sidelobe_db(&window) + 100.0).abs() < 0.5);
+        }
+        assert!((peak_sidelobe_db(&dolph_chebyshev(31, 60.0)) + 60.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_dolph_chebyshev_small_sizes() {
+        assert!(dolph_chebyshev(0, 100.0).is_empty());
+        assert_eq!(dolph_chebyshev(1, 100.0), vec![1.0]);
+    }
+}